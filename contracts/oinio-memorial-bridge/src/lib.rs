@@ -1,5 +1,108 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, String, symbol_short};
+use core::fmt::Write as _;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, String, Symbol,
+    TryIntoVal, Val, Vec,
+};
+
+/// Key for a single (from, spender) allowance entry.
+#[contracttype]
+#[derive(Clone)]
+pub struct AllowanceKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+/// An approved amount plus the ledger sequence after which it no longer applies.
+#[contracttype]
+#[derive(Clone)]
+pub struct AllowanceValue {
+    pub amount: u64,
+    pub expiration_ledger: u32,
+}
+
+/// One keeper's approval of a specific proposed operation.
+#[contracttype]
+#[derive(Clone)]
+pub struct ApprovalKey {
+    pub op_hash: BytesN<32>,
+    pub keeper: Address,
+}
+
+/// One keeper's approval of rotating admin authority to a specific
+/// `new_admin`. Kept separate from `ApprovalKey` (rather than hashing
+/// `new_admin` into a generic `op_hash`) so what a keeper approved is
+/// always exactly the proposed destination address, with nothing to bind
+/// or spoof.
+#[contracttype]
+#[derive(Clone)]
+pub struct RotateApprovalKey {
+    pub new_admin: Address,
+    pub keeper: Address,
+}
+
+/// A named, HD-derived keeper identity: a human label and derivation index
+/// for the hardware wallet path that produced `address`.
+#[contracttype]
+#[derive(Clone)]
+pub struct KeeperRecord {
+    pub label: String,
+    pub derivation_index: u32,
+    pub address: Address,
+}
+
+/// Largest letter URL, in bytes, that an `execute` anchor payload may carry
+/// after its 40-byte content hash and timestamp prefix. Generous enough for
+/// a letter URL while keeping the stack buffer used to read it out bounded.
+const MAX_PAYLOAD_LEN: usize = 512;
+
+/// Domain tag mixed into the sha256 preimage a keeper actually approves
+/// for `execute`, so an anchor `op_hash` can never be replayed against a
+/// different guarded operation, even if the raw payload bytes happen to
+/// coincide (e.g. a URL that also parses as something else).
+const ANCHOR_OP_TAG: &[u8] = b"oinio-memorial-bridge:anchor:";
+
+/// One anchored letter: where it lives, a commitment to its exact content,
+/// who submitted it, and when.
+#[contracttype]
+#[derive(Clone)]
+pub struct LetterEntry {
+    pub url: String,
+    pub content_hash: BytesN<32>,
+    pub submitter: Address,
+    pub timestamp: u64,
+}
+
+/// The checks a wallet cares about before asking the user to sign a call,
+/// so it can render a confirmation screen instead of falling back to
+/// blind-signing. `sufficient_balance` and `threshold_met` read `true` when
+/// they don't apply to a given operation; `requires_admin_auth` reads `false`.
+#[contracttype]
+#[derive(Clone)]
+pub struct OpPreconditions {
+    pub sufficient_balance: bool,
+    pub threshold_met: bool,
+    pub requires_admin_auth: bool,
+}
+
+/// Writes formatted text into a fixed-size, stack-allocated buffer, so
+/// `describe_op` can build its summary without an allocator.
+struct StackWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> core::fmt::Write for StackWriter<'a> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let bytes = s.as_bytes();
+        if self.len + bytes.len() > self.buf.len() {
+            return Err(core::fmt::Error);
+        }
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+}
 
 #[contract]
 pub struct OinioMemorialBridge;
@@ -8,74 +111,1035 @@ pub struct OinioMemorialBridge;
 impl OinioMemorialBridge {
     /// THE GENESIS: Initialize the 1 Billion Supply
     /// This is the Root Sentence for the families
-    pub fn initialize(env: Env, admin: Address) {
+    ///
+    /// `keepers` are the Beloved Keepers entrusted with this memorial;
+    /// `threshold` is how many of them must approve a sensitive operation
+    /// before it takes effect: anchoring a letter (via `propose`/
+    /// `execute`) and rotating admin authority (via
+    /// `propose_rotate_admin`/`execute_rotate_admin`). Supply is fixed
+    /// here at genesis and there is no mint entry point, so minting isn't
+    /// one of the threshold-guarded operations.
+    pub fn initialize(env: Env, admin: Address, keepers: Vec<Address>, threshold: u32) {
         admin.require_auth();
-        
+
+        if threshold == 0 || threshold > keepers.len() {
+            panic!("threshold must be between 1 and the number of keepers");
+        }
+
         // Sacred message for the Beloved Keepers of the Northern Gateway
         let msg = String::from_str(&env, "OINIO: For the Beloved Keepers of the Northern Gateway. Not in vain.");
         env.storage().instance().set(&symbol_short!("MSG"), &msg);
-        
+
         // 1,000,000,000 OINIO
         env.storage().instance().set(&symbol_short!("SUPPLY"), &1_000_000_000u64);
         env.storage().persistent().set(&admin, &1_000_000_000u64);
+        env.storage().instance().set(&symbol_short!("ADMIN"), &admin);
+
+        env.storage().instance().set(&symbol_short!("KEEPERS"), &keepers);
+        env.storage().instance().set(&symbol_short!("THRESH"), &threshold);
+
+        Self::emit_genesis(&env, &admin, 1_000_000_000u64);
+    }
+
+    /// Record `keeper`'s approval of a proposed sensitive operation,
+    /// identified by the hash of its payload. Requires authorization from
+    /// `keeper`, and `keeper` must be one of the Beloved Keepers set at
+    /// `initialize`. Re-approving the same `op_hash` is a harmless no-op.
+    pub fn propose(env: Env, keeper: Address, op_hash: BytesN<32>) {
+        keeper.require_auth();
+        Self::require_keeper(&env, &keeper);
+
+        let key = ApprovalKey { op_hash, keeper };
+        env.storage().persistent().set(&key, &true);
+    }
+
+    /// Perform a sensitive operation once enough Beloved Keepers have
+    /// proposed it. `payload`, tagged with `ANCHOR_OP_TAG`, must hash to
+    /// `op_hash` via sha256, and the count of distinct keeper approvals
+    /// recorded for `op_hash` must meet the threshold. Approvals for
+    /// `op_hash` are cleared afterwards.
+    ///
+    /// Today the only guarded operation is anchoring a letter: `payload`
+    /// is `content_hash (32 bytes) || timestamp (8 bytes, big-endian) ||
+    /// url (UTF-8 bytes)`, so the content commitment and timestamp are
+    /// bound into what the keepers approve, not just the URL. `submitter`
+    /// must authorize the call itself, so attribution can't be forged by
+    /// a third party replaying an already-approved payload. Returns the
+    /// new letter's id.
+    pub fn execute(env: Env, op_hash: BytesN<32>, payload: Bytes, submitter: Address) -> u64 {
+        submitter.require_auth();
+
+        if Self::tagged_hash(&env, ANCHOR_OP_TAG, &payload) != op_hash {
+            panic!("payload does not match op_hash");
+        }
+        if !Self::approvals_met(&env, &op_hash) {
+            panic!("keeper threshold not met");
+        }
+        Self::clear_approvals(&env, &op_hash);
+
+        let (content_hash, timestamp, url) = Self::decode_anchor_payload(&env, &payload);
+        let id = Self::append_letter(&env, submitter, url.clone(), content_hash.clone(), timestamp);
+        Self::emit_anchor(&env, id, &url, &content_hash);
+        id
+    }
+
+    /// Migrate admin authority and balance to `new_admin`. Requires
+    /// authorization from the current admin. Use `execute_rotate_admin`
+    /// instead if the current admin key is unavailable and the Beloved
+    /// Keepers must rotate it by threshold.
+    pub fn rotate_admin(env: Env, new_admin: Address) {
+        let current_admin: Address = env.storage().instance().get(&symbol_short!("ADMIN")).unwrap();
+        current_admin.require_auth();
+        Self::do_rotate_admin(&env, &current_admin, &new_admin);
+    }
+
+    /// Record `keeper`'s approval of rotating admin authority to
+    /// `new_admin`. Requires authorization from `keeper`, and `keeper`
+    /// must be one of the Beloved Keepers set at `initialize`.
+    /// Re-approving the same `new_admin` is a harmless no-op.
+    pub fn propose_rotate_admin(env: Env, keeper: Address, new_admin: Address) {
+        keeper.require_auth();
+        Self::require_keeper(&env, &keeper);
+
+        let key = RotateApprovalKey { new_admin, keeper };
+        env.storage().persistent().set(&key, &true);
+    }
+
+    /// Migrate admin authority and balance to `new_admin` once enough
+    /// Beloved Keepers have called `propose_rotate_admin` for that exact
+    /// `new_admin`. Use this when the current admin key is lost. Unlike
+    /// `execute`, there's no separate payload to hash: what a keeper
+    /// approves is the destination address itself, so there's nothing to
+    /// bind, spoof, or replay from a different guarded operation.
+    pub fn execute_rotate_admin(env: Env, new_admin: Address) {
+        if !Self::rotate_approvals_met(&env, &new_admin) {
+            panic!("keeper threshold not met");
+        }
+        Self::clear_rotate_approvals(&env, &new_admin);
+
+        let current_admin: Address = env.storage().instance().get(&symbol_short!("ADMIN")).unwrap();
+        Self::do_rotate_admin(&env, &current_admin, &new_admin);
+    }
+
+    /// Register a human label and HD derivation index for a keeper
+    /// identity. Requires authorization from the current admin.
+    /// Re-registering an existing `label` overwrites its record in place.
+    pub fn register_keeper(env: Env, label: String, derivation_index: u32, address: Address) {
+        let admin: Address = env.storage().instance().get(&symbol_short!("ADMIN")).unwrap();
+        admin.require_auth();
+
+        let key = (symbol_short!("KPR"), label.clone());
+        let is_new = !env.storage().persistent().has(&key);
+        env.storage().persistent().set(
+            &key,
+            &KeeperRecord { label: label.clone(), derivation_index, address },
+        );
+
+        if is_new {
+            let mut labels: Vec<String> = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("KPRLBL"))
+                .unwrap_or(Vec::new(&env));
+            labels.push_back(label);
+            env.storage().instance().set(&symbol_short!("KPRLBL"), &labels);
+        }
+    }
+
+    /// Read a keeper's registration by its human label.
+    pub fn get_keeper(env: Env, label: String) -> Option<KeeperRecord> {
+        env.storage().persistent().get(&(symbol_short!("KPR"), label))
     }
 
-    /// THE ANCHOR: Lock your Facebook letter into the ledger
-    pub fn anchor_letter(env: Env, letter_url: String) {
-        env.storage().instance().set(&symbol_short!("LETTER"), &letter_url);
+    /// List every registered keeper identity.
+    pub fn list_keepers(env: Env) -> Vec<KeeperRecord> {
+        let labels: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("KPRLBL"))
+            .unwrap_or(Vec::new(&env));
+        let mut records = Vec::new(&env);
+        for label in labels.iter() {
+            if let Some(record) = Self::get_keeper(env.clone(), label) {
+                records.push_back(record);
+            }
+        }
+        records
     }
-    
+
     /// Read the memorial message
     pub fn get_message(env: Env) -> String {
         env.storage().instance().get(&symbol_short!("MSG")).unwrap()
     }
-    
-    /// Read the anchored letter URL
-    pub fn get_letter(env: Env) -> Option<String> {
-        env.storage().instance().get(&symbol_short!("LETTER"))
+
+    /// Read a single anchored letter by id.
+    pub fn get_letter_by_id(env: Env, id: u64) -> Option<LetterEntry> {
+        env.storage().persistent().get(&(symbol_short!("LETTER"), id))
+    }
+
+    /// How many letters have been anchored so far.
+    pub fn letter_count(env: Env) -> u64 {
+        env.storage().instance().get(&symbol_short!("LETCNT")).unwrap_or(0)
+    }
+
+    /// Recompute sha256 over `content_bytes` and check it matches the
+    /// commitment stored for letter `id`, proving a fetched document is
+    /// exactly the one that was memorialized.
+    pub fn verify_letter(env: Env, id: u64, content_bytes: Bytes) -> bool {
+        match Self::get_letter_by_id(env.clone(), id) {
+            Some(entry) => env.crypto().sha256(&content_bytes).to_bytes() == entry.content_hash,
+            None => false,
+        }
     }
-    
+
+    /// Render a plain-language summary of what a call to `op` with `args`
+    /// would do, so a hardware-wallet integrator can show the user a
+    /// meaningful confirmation screen instead of blind-signing opaque
+    /// bytes. Unknown operations describe themselves generically rather
+    /// than failing, since this is a read-only aid, not a guard.
+    pub fn describe_op(env: Env, op: Symbol, args: Vec<Val>) -> String {
+        let mut buf = [0u8; 256];
+        let mut w = StackWriter { buf: &mut buf, len: 0 };
+
+        let _ = if op == Symbol::new(&env, "transfer") {
+            let amount: u64 = args.get(2).unwrap().try_into_val(&env).unwrap();
+            write!(w, "Transfer {} OINIO to the given recipient", amount)
+        } else if op == Symbol::new(&env, "transfer_from") {
+            let amount: u64 = args.get(3).unwrap().try_into_val(&env).unwrap();
+            write!(w, "Transfer {} OINIO from one address to another using an approved allowance", amount)
+        } else if op == Symbol::new(&env, "approve") {
+            let amount: u64 = args.get(2).unwrap().try_into_val(&env).unwrap();
+            write!(w, "Approve a spender to move up to {} OINIO", amount)
+        } else if op == Symbol::new(&env, "burn") {
+            let amount: u64 = args.get(1).unwrap().try_into_val(&env).unwrap();
+            write!(w, "Burn {} OINIO, permanently reducing the supply", amount)
+        } else if op == Symbol::new(&env, "burn_from") {
+            let amount: u64 = args.get(2).unwrap().try_into_val(&env).unwrap();
+            write!(w, "Burn {} OINIO from an address using an approved allowance", amount)
+        } else if op == Symbol::new(&env, "propose") {
+            let op_hash: BytesN<32> = args.get(1).unwrap().try_into_val(&env).unwrap();
+            let _ = write!(w, "Approve pending operation ");
+            Self::write_hex(&mut w, &op_hash)
+        } else if op == Symbol::new(&env, "execute") {
+            let payload: Bytes = args.get(1).unwrap().try_into_val(&env).unwrap();
+            let (content_hash, _, _) = Self::decode_anchor_payload(&env, &payload);
+            let _ = write!(w, "Anchor a letter, committing to content hash ");
+            Self::write_hex(&mut w, &content_hash)
+        } else if op == Symbol::new(&env, "rotate_admin")
+            || op == Symbol::new(&env, "propose_rotate_admin")
+            || op == Symbol::new(&env, "execute_rotate_admin")
+        {
+            write!(w, "Rotate the admin key to a new address")
+        } else if op == Symbol::new(&env, "register_keeper") {
+            write!(w, "Register a named keeper identity")
+        } else {
+            write!(w, "Call an unrecognized operation with {} argument(s)", args.len())
+        };
+
+        let wlen = w.len;
+        String::from_str(&env, core::str::from_utf8(&buf[..wlen]).unwrap_or("<unrenderable operation>"))
+    }
+
+    /// Surface the preconditions a wallet would want to check before
+    /// asking the user to sign a call to `op` with `args`: whether the
+    /// relevant balance covers the amount, whether a pending multisig
+    /// proposal has met its threshold, and whether admin authorization is
+    /// required. `sufficient_balance` and `threshold_met` read `true` when
+    /// they don't apply to `op`; `requires_admin_auth` reads `false`.
+    pub fn check_preconditions(env: Env, op: Symbol, args: Vec<Val>) -> OpPreconditions {
+        if op == Symbol::new(&env, "transfer") {
+            let from: Address = args.get(0).unwrap().try_into_val(&env).unwrap();
+            let amount: u64 = args.get(2).unwrap().try_into_val(&env).unwrap();
+            OpPreconditions {
+                sufficient_balance: Self::read_balance(&env, &from) >= amount,
+                threshold_met: true,
+                requires_admin_auth: false,
+            }
+        } else if op == Symbol::new(&env, "burn") {
+            let from: Address = args.get(0).unwrap().try_into_val(&env).unwrap();
+            let amount: u64 = args.get(1).unwrap().try_into_val(&env).unwrap();
+            OpPreconditions {
+                sufficient_balance: Self::read_balance(&env, &from) >= amount,
+                threshold_met: true,
+                requires_admin_auth: false,
+            }
+        } else if op == Symbol::new(&env, "transfer_from") || op == Symbol::new(&env, "burn_from") {
+            let from: Address = args.get(1).unwrap().try_into_val(&env).unwrap();
+            let amount: u64 = args.get(args.len() - 1).unwrap().try_into_val(&env).unwrap();
+            OpPreconditions {
+                sufficient_balance: Self::read_balance(&env, &from) >= amount,
+                threshold_met: true,
+                requires_admin_auth: false,
+            }
+        } else if op == Symbol::new(&env, "execute") {
+            let op_hash: BytesN<32> = args.get(0).unwrap().try_into_val(&env).unwrap();
+            OpPreconditions {
+                sufficient_balance: true,
+                threshold_met: Self::approvals_met(&env, &op_hash),
+                requires_admin_auth: false,
+            }
+        } else if op == Symbol::new(&env, "execute_rotate_admin") {
+            let new_admin: Address = args.get(0).unwrap().try_into_val(&env).unwrap();
+            OpPreconditions {
+                sufficient_balance: true,
+                threshold_met: Self::rotate_approvals_met(&env, &new_admin),
+                requires_admin_auth: false,
+            }
+        } else if op == Symbol::new(&env, "rotate_admin") || op == Symbol::new(&env, "register_keeper") {
+            OpPreconditions { sufficient_balance: true, threshold_met: true, requires_admin_auth: true }
+        } else {
+            OpPreconditions { sufficient_balance: true, threshold_met: true, requires_admin_auth: false }
+        }
+    }
+
     /// Read the total supply
     pub fn get_supply(env: Env) -> u64 {
         env.storage().instance().get(&symbol_short!("SUPPLY")).unwrap()
     }
+
+    /// Read the balance held by `id`. Addresses that have never received
+    /// OINIO simply have a balance of zero.
+    ///
+    /// This covers the movement half of SEP-41 (`transfer`, `transfer_from`,
+    /// `approve`, `allowance`, `balance`, `burn`, `burn_from`); the
+    /// metadata half (`decimals`, `name`, `symbol`) isn't implemented yet.
+    pub fn balance(env: Env, id: Address) -> u64 {
+        Self::read_balance(&env, &id)
+    }
+
+    /// Move `amount` from `from` to `to`. Requires authorization from `from`.
+    pub fn transfer(env: Env, from: Address, to: Address, amount: u64) {
+        from.require_auth();
+        Self::do_transfer(&env, &from, &to, amount);
+    }
+
+    /// Move `amount` from `from` to `to` on behalf of `spender`, consuming
+    /// the allowance `from` previously approved for `spender`.
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: u64) {
+        spender.require_auth();
+        Self::spend_allowance(&env, &from, &spender, amount);
+        Self::do_transfer(&env, &from, &to, amount);
+    }
+
+    /// Let `spender` move up to `amount` out of `from`'s balance until
+    /// `expiration_ledger`. Requires authorization from `from`.
+    pub fn approve(env: Env, from: Address, spender: Address, amount: u64, expiration_ledger: u32) {
+        from.require_auth();
+        let key = AllowanceKey { from, spender };
+        env.storage().persistent().set(
+            &key,
+            &AllowanceValue { amount, expiration_ledger },
+        );
+    }
+
+    /// Read how much `spender` may still move out of `from`'s balance.
+    /// An expired approval reads back as zero.
+    pub fn allowance(env: Env, from: Address, spender: Address) -> u64 {
+        Self::read_allowance(&env, &from, &spender)
+    }
+
+    /// Destroy `amount` from `from`'s balance, shrinking the total supply.
+    /// Requires authorization from `from`.
+    pub fn burn(env: Env, from: Address, amount: u64) {
+        from.require_auth();
+        Self::do_burn(&env, &from, amount);
+    }
+
+    /// Destroy `amount` from `from`'s balance on behalf of `spender`,
+    /// consuming the allowance `from` previously approved for `spender`.
+    pub fn burn_from(env: Env, spender: Address, from: Address, amount: u64) {
+        spender.require_auth();
+        Self::spend_allowance(&env, &from, &spender, amount);
+        Self::do_burn(&env, &from, amount);
+    }
+
+    fn read_balance(env: &Env, id: &Address) -> u64 {
+        env.storage().persistent().get(id).unwrap_or(0)
+    }
+
+    fn write_balance(env: &Env, id: &Address, amount: u64) {
+        env.storage().persistent().set(id, &amount);
+    }
+
+    fn spend_balance(env: &Env, from: &Address, amount: u64) {
+        let balance = Self::read_balance(env, from);
+        if amount > balance {
+            panic!("insufficient balance");
+        }
+        Self::write_balance(env, from, balance.saturating_sub(amount));
+    }
+
+    fn do_transfer(env: &Env, from: &Address, to: &Address, amount: u64) {
+        Self::spend_balance(env, from, amount);
+        let to_balance = Self::read_balance(env, to);
+        Self::write_balance(env, to, to_balance.saturating_add(amount));
+        Self::emit_transfer(env, from, to, amount);
+    }
+
+    fn do_burn(env: &Env, from: &Address, amount: u64) {
+        Self::spend_balance(env, from, amount);
+        Self::decrease_supply(env, amount);
+        Self::emit_burn(env, from, amount);
+    }
+
+    fn read_allowance(env: &Env, from: &Address, spender: &Address) -> u64 {
+        let key = AllowanceKey { from: from.clone(), spender: spender.clone() };
+        match env.storage().persistent().get::<_, AllowanceValue>(&key) {
+            Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance.amount,
+            _ => 0,
+        }
+    }
+
+    fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: u64) {
+        let key = AllowanceKey { from: from.clone(), spender: spender.clone() };
+        let remaining = Self::read_allowance(env, from, spender);
+        if amount > remaining {
+            panic!("insufficient allowance");
+        }
+        // `from` may never have called `approve`, in which case there's no
+        // record to re-fetch — a zero-amount spend is still valid then.
+        let expiration_ledger = env
+            .storage()
+            .persistent()
+            .get::<_, AllowanceValue>(&key)
+            .map(|allowance| allowance.expiration_ledger)
+            .unwrap_or(0);
+        env.storage().persistent().set(
+            &key,
+            &AllowanceValue { amount: remaining.saturating_sub(amount), expiration_ledger },
+        );
+    }
+
+    fn decrease_supply(env: &Env, amount: u64) {
+        let supply: u64 = env.storage().instance().get(&symbol_short!("SUPPLY")).unwrap();
+        env.storage().instance().set(&symbol_short!("SUPPLY"), &supply.saturating_sub(amount));
+    }
+
+    /// Publish the genesis event: `("genesis", admin)` topics, carrying the
+    /// initial supply as data.
+    fn emit_genesis(env: &Env, admin: &Address, supply: u64) {
+        env.events().publish((symbol_short!("genesis"), admin.clone()), supply);
+    }
+
+    /// Publish the anchor event: `("anchor", id)` topics, carrying the
+    /// letter's URL and content hash as data.
+    fn emit_anchor(env: &Env, id: u64, url: &String, content_hash: &BytesN<32>) {
+        env.events().publish((symbol_short!("anchor"), id), (url.clone(), content_hash.clone()));
+    }
+
+    /// Publish the transfer event: `("transfer", from, to)` topics,
+    /// carrying the amount moved as data.
+    fn emit_transfer(env: &Env, from: &Address, to: &Address, amount: u64) {
+        env.events().publish((symbol_short!("transfer"), from.clone(), to.clone()), amount);
+    }
+
+    /// Publish the burn event: `("burn", from)` topics, carrying the
+    /// amount destroyed as data.
+    fn emit_burn(env: &Env, from: &Address, amount: u64) {
+        env.events().publish((symbol_short!("burn"), from.clone()), amount);
+    }
+
+    /// Publish the rotation event: `("rotate", old_admin)` topics,
+    /// carrying the new admin as data.
+    fn emit_rotate(env: &Env, old_admin: &Address, new_admin: &Address) {
+        env.events().publish((symbol_short!("rotate"), old_admin.clone()), new_admin.clone());
+    }
+
+    fn do_rotate_admin(env: &Env, current_admin: &Address, new_admin: &Address) {
+        let balance = Self::read_balance(env, current_admin);
+        Self::write_balance(env, current_admin, 0);
+        let new_balance = Self::read_balance(env, new_admin).saturating_add(balance);
+        Self::write_balance(env, new_admin, new_balance);
+
+        env.storage().instance().set(&symbol_short!("ADMIN"), new_admin);
+        Self::emit_rotate(env, current_admin, new_admin);
+    }
+
+    fn append_letter(env: &Env, submitter: Address, url: String, content_hash: BytesN<32>, timestamp: u64) -> u64 {
+        let id: u64 = env.storage().instance().get(&symbol_short!("LETCNT")).unwrap_or(0);
+        let entry = LetterEntry { url, content_hash, submitter, timestamp };
+        env.storage().persistent().set(&(symbol_short!("LETTER"), id), &entry);
+        env.storage().instance().set(&symbol_short!("LETCNT"), &(id + 1));
+        id
+    }
+
+    fn keepers(env: &Env) -> Vec<Address> {
+        env.storage().instance().get(&symbol_short!("KEEPERS")).unwrap()
+    }
+
+    fn require_keeper(env: &Env, keeper: &Address) {
+        if !Self::keepers(env).contains(keeper) {
+            panic!("not a recognized keeper");
+        }
+    }
+
+    fn approvals_met(env: &Env, op_hash: &BytesN<32>) -> bool {
+        let threshold: u32 = env.storage().instance().get(&symbol_short!("THRESH")).unwrap();
+        let mut approvals = 0u32;
+        for keeper in Self::keepers(env).iter() {
+            let key = ApprovalKey { op_hash: op_hash.clone(), keeper };
+            if env.storage().persistent().get::<_, bool>(&key).unwrap_or(false) {
+                approvals += 1;
+            }
+        }
+        approvals >= threshold
+    }
+
+    fn clear_approvals(env: &Env, op_hash: &BytesN<32>) {
+        for keeper in Self::keepers(env).iter() {
+            let key = ApprovalKey { op_hash: op_hash.clone(), keeper };
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    fn rotate_approvals_met(env: &Env, new_admin: &Address) -> bool {
+        let threshold: u32 = env.storage().instance().get(&symbol_short!("THRESH")).unwrap();
+        let mut approvals = 0u32;
+        for keeper in Self::keepers(env).iter() {
+            let key = RotateApprovalKey { new_admin: new_admin.clone(), keeper };
+            if env.storage().persistent().get::<_, bool>(&key).unwrap_or(false) {
+                approvals += 1;
+            }
+        }
+        approvals >= threshold
+    }
+
+    fn clear_rotate_approvals(env: &Env, new_admin: &Address) {
+        for keeper in Self::keepers(env).iter() {
+            let key = RotateApprovalKey { new_admin: new_admin.clone(), keeper };
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Hash `tag || payload`, so two different guarded operations that
+    /// happen to be proposed over the same raw payload bytes never share
+    /// an `op_hash`.
+    fn tagged_hash(env: &Env, tag: &[u8], payload: &Bytes) -> BytesN<32> {
+        let mut tagged = Bytes::from_slice(env, tag);
+        tagged.append(payload);
+        env.crypto().sha256(&tagged).to_bytes()
+    }
+
+    /// Decode an `execute` anchor payload into its `(content_hash,
+    /// timestamp, url)` parts. The layout is `content_hash (32 bytes) ||
+    /// timestamp (8 bytes, big-endian) || url (UTF-8 bytes)`, so binding
+    /// `payload` to `op_hash` binds all three fields, not just the URL.
+    /// Panics if the payload is too short, the URL exceeds
+    /// `MAX_PAYLOAD_LEN`, or the URL isn't valid UTF-8.
+    fn decode_anchor_payload(env: &Env, payload: &Bytes) -> (BytesN<32>, u64, String) {
+        let len = payload.len() as usize;
+        if len < 40 {
+            panic!("anchor payload too short");
+        }
+        let url_len = len - 40;
+        if url_len > MAX_PAYLOAD_LEN {
+            panic!("payload too long");
+        }
+
+        let mut content_hash_bytes = [0u8; 32];
+        for (i, byte) in content_hash_bytes.iter_mut().enumerate() {
+            *byte = payload.get(i as u32).unwrap();
+        }
+        let mut timestamp_bytes = [0u8; 8];
+        for (i, byte) in timestamp_bytes.iter_mut().enumerate() {
+            *byte = payload.get((32 + i) as u32).unwrap();
+        }
+        let mut url_buf = [0u8; MAX_PAYLOAD_LEN];
+        for (i, byte) in url_buf.iter_mut().take(url_len).enumerate() {
+            *byte = payload.get((40 + i) as u32).unwrap();
+        }
+
+        let content_hash = BytesN::from_array(env, &content_hash_bytes);
+        let timestamp = u64::from_be_bytes(timestamp_bytes);
+        let url = core::str::from_utf8(&url_buf[..url_len]).unwrap_or_else(|_| panic!("payload is not valid utf-8"));
+        (content_hash, timestamp, String::from_str(env, url))
+    }
+
+    fn write_hex(w: &mut StackWriter, bytes: &BytesN<32>) -> core::fmt::Result {
+        for byte in bytes.to_array().iter() {
+            write!(w, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
-    use soroban_sdk::Env;
+    use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+    use soroban_sdk::{Env, IntoVal};
+
+    /// Build an `execute` anchor payload: `content_hash || timestamp || url`.
+    fn anchor_payload(env: &Env, content_hash: &BytesN<32>, timestamp: u64, url: &[u8]) -> Bytes {
+        let mut payload = Bytes::from_slice(env, &content_hash.to_array());
+        payload.append(&Bytes::from_slice(env, &timestamp.to_be_bytes()));
+        payload.append(&Bytes::from_slice(env, url));
+        payload
+    }
+
+    /// The `op_hash` a keeper would actually approve for an anchor payload.
+    fn anchor_op_hash(env: &Env, payload: &Bytes) -> BytesN<32> {
+        OinioMemorialBridge::tagged_hash(env, ANCHOR_OP_TAG, payload)
+    }
 
     #[test]
     fn test_initialize() {
         let env = Env::default();
         let contract_id = env.register_contract(None, OinioMemorialBridge);
         let client = OinioMemorialBridgeClient::new(&env, &contract_id);
-        
+
         let admin = Address::generate(&env);
         env.mock_all_auths();
-        
-        client.initialize(&admin);
-        
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+
         assert_eq!(client.get_supply(), 1_000_000_000u64);
         assert_eq!(client.get_message(), String::from_str(&env, "OINIO: For the Beloved Keepers of the Northern Gateway. Not in vain."));
     }
 
     #[test]
-    fn test_anchor_letter() {
+    fn test_anchor_letter_via_single_keeper() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+
+        let content_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let payload = anchor_payload(&env, &content_hash, 12345u64, b"https://facebook.com/letter");
+        let op_hash = anchor_op_hash(&env, &payload);
+
+        client.propose(&admin, &op_hash);
+        let id = client.execute(&op_hash, &payload, &admin);
+
+        assert_eq!(id, 0);
+        assert_eq!(client.letter_count(), 1);
+        let entry = client.get_letter_by_id(&id).unwrap();
+        assert_eq!(entry.url, String::from_str(&env, "https://facebook.com/letter"));
+        assert_eq!(entry.content_hash, content_hash);
+        assert_eq!(entry.submitter, admin);
+        assert_eq!(entry.timestamp, 12345u64);
+    }
+
+    #[test]
+    fn test_anchor_letter_requires_threshold() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let keeper_b = Address::generate(&env);
+        let keeper_c = Address::generate(&env);
+        env.mock_all_auths();
+
+        let keepers = Vec::from_array(&env, [admin.clone(), keeper_b.clone(), keeper_c.clone()]);
+        client.initialize(&admin, &keepers, &2u32);
+
+        let content_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let payload = anchor_payload(&env, &content_hash, 12345u64, b"https://facebook.com/letter");
+        let op_hash = anchor_op_hash(&env, &payload);
+
+        client.propose(&admin, &op_hash);
+        assert_eq!(client.letter_count(), 0);
+
+        // Re-proposing with the same keeper is a no-op, not a second vote.
+        client.propose(&admin, &op_hash);
+        assert_eq!(client.letter_count(), 0);
+
+        client.propose(&keeper_b, &op_hash);
+        client.execute(&op_hash, &payload, &admin);
+
+        assert_eq!(client.letter_count(), 1);
+    }
+
+    #[test]
+    fn test_verify_letter() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+
+        let content_bytes = Bytes::from_slice(&env, b"the actual letter text");
+        let content_hash = env.crypto().sha256(&content_bytes).to_bytes();
+        let payload = anchor_payload(&env, &content_hash, 1u64, b"https://facebook.com/letter");
+        let op_hash = anchor_op_hash(&env, &payload);
+
+        client.propose(&admin, &op_hash);
+        let id = client.execute(&op_hash, &payload, &admin);
+
+        assert!(client.verify_letter(&id, &content_bytes));
+        assert!(!client.verify_letter(&id, &Bytes::from_slice(&env, b"a forged letter")));
+        assert!(!client.verify_letter(&(id + 1), &content_bytes));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a recognized keeper")]
+    fn test_propose_rejects_unknown_keeper() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+
+        let payload = Bytes::from_slice(&env, b"https://facebook.com/letter");
+        let op_hash = env.crypto().sha256(&payload).to_bytes();
+        client.propose(&stranger, &op_hash);
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold must be between 1 and the number of keepers")]
+    fn test_initialize_rejects_threshold_over_keeper_count() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &2u32);
+    }
+
+    #[test]
+    fn test_transfer() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+        client.transfer(&admin, &recipient, &400u64);
+
+        assert_eq!(client.balance(&admin), 1_000_000_000u64 - 400);
+        assert_eq!(client.balance(&recipient), 400u64);
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient balance")]
+    fn test_transfer_insufficient_balance() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+        client.transfer(&admin, &recipient, &1_000_000_001u64);
+    }
+
+    #[test]
+    fn test_approve_and_transfer_from() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+        client.approve(&admin, &spender, &500u64, &1000u32);
+        assert_eq!(client.allowance(&admin, &spender), 500u64);
+
+        client.transfer_from(&spender, &admin, &recipient, &200u64);
+
+        assert_eq!(client.balance(&recipient), 200u64);
+        assert_eq!(client.allowance(&admin, &spender), 300u64);
+    }
+
+    #[test]
+    fn test_transfer_from_zero_amount_without_prior_approval() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+
+        // No `approve` was ever recorded for (admin, spender); a zero-amount
+        // spend is still a valid no-op rather than a panic.
+        client.transfer_from(&spender, &admin, &recipient, &0u64);
+
+        assert_eq!(client.balance(&recipient), 0u64);
+        assert_eq!(client.allowance(&admin, &spender), 0u64);
+    }
+
+    #[test]
+    fn test_allowance_expires() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let spender = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+        client.approve(&admin, &spender, &500u64, &5u32);
+
+        env.ledger().set_sequence_number(10);
+
+        assert_eq!(client.allowance(&admin, &spender), 0u64);
+    }
+
+    #[test]
+    fn test_burn() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+        client.burn(&admin, &1_000u64);
+
+        assert_eq!(client.balance(&admin), 1_000_000_000u64 - 1_000);
+        assert_eq!(client.get_supply(), 1_000_000_000u64 - 1_000);
+    }
+
+    #[test]
+    fn test_burn_from() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let spender = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+        client.approve(&admin, &spender, &500u64, &1000u32);
+        client.burn_from(&spender, &admin, &300u64);
+
+        assert_eq!(client.balance(&admin), 1_000_000_000u64 - 300);
+        assert_eq!(client.get_supply(), 1_000_000_000u64 - 300);
+        assert_eq!(client.allowance(&admin, &spender), 200u64);
+    }
+
+    #[test]
+    fn test_describe_op_transfer() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let recipient = Address::generate(&env);
+
+        let args: Vec<Val> = Vec::from_array(
+            &env,
+            [admin.into_val(&env), recipient.into_val(&env), 400u64.into_val(&env)],
+        );
+        let description = client.describe_op(&Symbol::new(&env, "transfer"), &args);
+
+        assert_eq!(description, String::from_str(&env, "Transfer 400 OINIO to the given recipient"));
+    }
+
+    #[test]
+    fn test_check_preconditions_transfer() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+
+        let args: Vec<Val> = Vec::from_array(
+            &env,
+            [admin.into_val(&env), recipient.into_val(&env), 2_000_000_000u64.into_val(&env)],
+        );
+        let preconditions = client.check_preconditions(&Symbol::new(&env, "transfer"), &args);
+
+        assert!(!preconditions.sufficient_balance);
+        assert!(preconditions.threshold_met);
+        assert!(!preconditions.requires_admin_auth);
+    }
+
+    #[test]
+    fn test_events_genesis_transfer_burn() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+        client.transfer(&admin, &recipient, &400u64);
+        client.burn(&recipient, &100u64);
+
+        assert_eq!(
+            env.events().all(),
+            Vec::from_array(
+                &env,
+                [
+                    (
+                        contract_id.clone(),
+                        (symbol_short!("genesis"), admin.clone()).into_val(&env),
+                        1_000_000_000u64.into_val(&env),
+                    ),
+                    (
+                        contract_id.clone(),
+                        (symbol_short!("transfer"), admin.clone(), recipient.clone()).into_val(&env),
+                        400u64.into_val(&env),
+                    ),
+                    (
+                        contract_id.clone(),
+                        (symbol_short!("burn"), recipient.clone()).into_val(&env),
+                        100u64.into_val(&env),
+                    ),
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn test_events_anchor() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+
+        let content_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let payload = anchor_payload(&env, &content_hash, 12345u64, b"https://facebook.com/letter");
+        let op_hash = anchor_op_hash(&env, &payload);
+
+        client.propose(&admin, &op_hash);
+        let id = client.execute(&op_hash, &payload, &admin);
+
+        let url = String::from_str(&env, "https://facebook.com/letter");
+        assert_eq!(
+            env.events().all(),
+            Vec::from_array(
+                &env,
+                [
+                    (
+                        contract_id.clone(),
+                        (symbol_short!("genesis"), admin.clone()).into_val(&env),
+                        1_000_000_000u64.into_val(&env),
+                    ),
+                    (
+                        contract_id.clone(),
+                        (symbol_short!("anchor"), id).into_val(&env),
+                        (url, content_hash).into_val(&env),
+                    ),
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn test_rotate_admin() {
         let env = Env::default();
         let contract_id = env.register_contract(None, OinioMemorialBridge);
         let client = OinioMemorialBridgeClient::new(&env, &contract_id);
-        
+
         let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
         env.mock_all_auths();
-        
-        client.initialize(&admin);
-        
-        let letter_url = String::from_str(&env, "https://facebook.com/letter");
-        client.anchor_letter(&letter_url);
-        
-        assert_eq!(client.get_letter(), Some(letter_url));
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+        client.rotate_admin(&new_admin);
+
+        assert_eq!(client.balance(&admin), 0u64);
+        assert_eq!(client.balance(&new_admin), 1_000_000_000u64);
+    }
+
+    #[test]
+    fn test_execute_rotate_admin_via_threshold() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let keeper_b = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        let keepers = Vec::from_array(&env, [admin.clone(), keeper_b.clone()]);
+        client.initialize(&admin, &keepers, &2u32);
+
+        client.propose_rotate_admin(&admin, &new_admin);
+        client.propose_rotate_admin(&keeper_b, &new_admin);
+        client.execute_rotate_admin(&new_admin);
+
+        assert_eq!(client.balance(&admin), 0u64);
+        assert_eq!(client.balance(&new_admin), 1_000_000_000u64);
+    }
+
+    #[test]
+    #[should_panic(expected = "keeper threshold not met")]
+    fn test_execute_rotate_admin_rejects_unapproved_destination() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let keeper_b = Address::generate(&env);
+        let approved_admin = Address::generate(&env);
+        let attacker = Address::generate(&env);
+        env.mock_all_auths();
+
+        let keepers = Vec::from_array(&env, [admin.clone(), keeper_b.clone()]);
+        client.initialize(&admin, &keepers, &2u32);
+
+        client.propose_rotate_admin(&admin, &approved_admin);
+        client.propose_rotate_admin(&keeper_b, &approved_admin);
+
+        // Keepers approved rotating to `approved_admin`, not `attacker` —
+        // their approval can't be redirected to a different destination.
+        client.execute_rotate_admin(&attacker);
+    }
+
+    #[test]
+    fn test_keeper_registry() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, OinioMemorialBridge);
+        let client = OinioMemorialBridgeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let hardware_key = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin, &Vec::from_array(&env, [admin.clone()]), &1u32);
+
+        let label = String::from_str(&env, "mom's ledger");
+        client.register_keeper(&label, &0u32, &hardware_key);
+
+        let record = client.get_keeper(&label).unwrap();
+        assert_eq!(record.derivation_index, 0u32);
+        assert_eq!(record.address, hardware_key);
+
+        let keepers = client.list_keepers();
+        assert_eq!(keepers.len(), 1);
+        assert_eq!(keepers.get(0).unwrap().label, label);
     }
 }